@@ -5,36 +5,170 @@ use std::io;
 fn main() {
     println!("Welcome to the Guessing Game!");
 
-    let secret_number = rand::thread_rng().gen_range(1..=100);
+    let (low, high) = select_range();
+    let max_attempts = attempts_for_range(low, high);
 
-    println!("The secret number is: {secret_number}");
+    println!("I've picked a number between {low} and {high}. Can you guess what it is?");
+    println!("You have {max_attempts} guesses each round.");
 
-    println!("I've picked a number between 1 and 100. Can you guess what it is?");
+    let mut rounds_played = 0;
+    let mut best_attempts: Option<u32> = None;
 
+    'session: loop {
+        let secret_number = rand::thread_rng().gen_range(low..=high);
+
+        let mut attempts = (1..=max_attempts).rev();
+
+        let result: Result<u32, ()> = 'attempts: loop {
+            while let Some(remaining) = attempts.next() {
+                println!("Guesses remaining: {remaining}");
+
+                let guess: u32 = loop {
+                    let mut guess = String::new();
+
+                    io::stdin()
+                        .read_line(&mut guess)
+                        .expect("Failed to read line.");
+
+                    match guess.trim().parse() {
+                        Ok(num) => break num,
+                        Err(_) => {
+                            println!(
+                                "That doesn't seem like a number. Please enter a valid number:"
+                            );
+                            continue;
+                        }
+                    }
+                };
+
+                println!("You guessed: {guess}");
+
+                match guess.cmp(&secret_number) {
+                    Ordering::Less => println!("Too low! Try again:"),
+                    Ordering::Greater => println!("Too high! Try again:"),
+                    Ordering::Equal => {
+                        println!("Congratulations! You guessed the right number!");
+                        break 'attempts Ok(max_attempts - remaining + 1);
+                    }
+                }
+            }
+
+            println!("Out of guesses! The number was {secret_number}.");
+            break 'attempts Err(());
+        };
+
+        rounds_played += 1;
+
+        match result {
+            Ok(attempt) => {
+                let score = (max_attempts - attempt + 1) * 10;
+                println!("You won in {attempt} guess(es)! Score: {score}");
+                best_attempts = Some(best_attempts.map_or(attempt, |best| best.min(attempt)));
+            }
+            Err(()) => println!("Final score: 0"),
+        }
+
+        loop {
+            println!("Play again? (y/n)");
+
+            let mut answer = String::new();
+
+            io::stdin()
+                .read_line(&mut answer)
+                .expect("Failed to read line.");
+
+            match answer.trim() {
+                "y" => continue 'session,
+                "n" => break 'session,
+                _ => {
+                    println!("Please answer with 'y' or 'n'.");
+                    continue;
+                }
+            }
+        }
+    }
+
+    match best_attempts {
+        Some(best) => println!("You played {rounds_played} round(s), best was {best} guesses."),
+        None => println!("You played {rounds_played} round(s) and never won."),
+    }
+}
+
+// Prompts for a difficulty (or a custom range) until a valid choice is made.
+fn select_range() -> (u32, u32) {
     loop {
-        let mut guess = String::new();
+        println!(
+            "Choose a difficulty: (e)asy [1-10], (m)edium [1-100], (h)ard [1-1000], or (c)ustom:"
+        );
+
+        let mut choice = String::new();
 
         io::stdin()
-            .read_line(&mut guess)
+            .read_line(&mut choice)
             .expect("Failed to read line.");
 
-        let guess: u32 = match guess.trim().parse() {
-            Ok(num) => num,
-            Err(_) => {
-                println!("That doesn't seem like a number. Please enter a valid number:");
-                continue;
+        match choice.trim().to_lowercase().as_str() {
+            "e" | "easy" => return (1, 10),
+            "m" | "medium" => return (1, 100),
+            "h" | "hard" => return (1, 1000),
+            "c" | "custom" => {
+                if let Some(range) = read_custom_range() {
+                    return range;
+                }
             }
-        };
+            _ => println!(
+                "That doesn't seem like a valid choice. Please enter 'e', 'm', 'h', or 'c'."
+            ),
+        }
+    }
+}
 
-        println!("You guessed: {guess}");
+// Reads a "low high" pair for the custom difficulty, returning None on bad input
+// so the caller can re-prompt from select_range's loop.
+fn read_custom_range() -> Option<(u32, u32)> {
+    println!("Enter a custom range as \"low high\" (e.g. \"1 50\"):");
 
-        match guess.cmp(&secret_number) {
-            Ordering::Less => println!("Too low! Try again:"),
-            Ordering::Greater => println!("Too high! Try again:"),
-            Ordering::Equal => {
-                println!("Congratulations! You guessed the right number!");
-                break;
+    let mut input = String::new();
+
+    io::stdin()
+        .read_line(&mut input)
+        .expect("Failed to read line.");
+
+    let mut parts = input.trim().split_whitespace();
+
+    let (low, high) = match (parts.next(), parts.next()) {
+        (Some(low), Some(high)) => match (low.parse::<u32>(), high.parse::<u32>()) {
+            (Ok(low), Ok(high)) => (low, high),
+            _ => {
+                println!("That doesn't seem like two numbers. Please try again.");
+                return None;
             }
+        },
+        _ => {
+            println!("Please enter two numbers separated by a space.");
+            return None;
         }
+    };
+
+    if low >= high {
+        println!("The low bound must be less than the high bound. Please try again.");
+        return None;
+    }
+
+    Some((low, high))
+}
+
+// Scales the attempt budget with the chosen range, giving a couple of guesses
+// of slack beyond the number needed to binary-search the range.
+fn attempts_for_range(low: u32, high: u32) -> u32 {
+    let span = u64::from(high) - u64::from(low) + 1;
+
+    let mut attempts: u32 = 0;
+    let mut covered: u64 = 1;
+    while covered <= span {
+        covered *= 2;
+        attempts += 1;
     }
+
+    attempts + 2
 }